@@ -0,0 +1,948 @@
+use std::collections::HashSet;
+use std::io;
+
+use serde::de::{
+    self, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess, VariantAccess,
+    Visitor,
+};
+use serde::Deserialize;
+
+use crate::error::{Error, Position, Result, SpannedError};
+use crate::read::{IoRead, Read, SliceRead};
+
+/// A structural deserializer for Hjson documents, generic over where the
+/// bytes come from (see [`crate::read::Read`]).
+///
+/// Most users will want [`from_str`] or [`from_reader`] rather than
+/// using this type directly.
+pub struct Deserializer<R> {
+    read: R,
+    line: usize,
+    col: usize,
+    /// Whether we are still looking at the very first value of the
+    /// document, which (per the Hjson spec) is allowed to be an object
+    /// whose surrounding `{ }` have been omitted.
+    at_root: bool,
+    /// Whether a repeated key within the same object should be rejected
+    /// rather than silently overwriting the earlier value.
+    strict_keys: bool,
+    /// Whether Java-style `/* */` block comments are recognized.
+    allow_block_comments: bool,
+    /// How many objects/arrays deep the parser currently is.
+    depth: usize,
+    /// The deepest `depth` is allowed to go before
+    /// [`Error::DepthLimitExceeded`] is raised, if any.
+    max_depth: Option<usize>,
+}
+
+impl<'a> Deserializer<SliceRead<'a>> {
+    // Named to mirror `crate::from_str` / `serde_json::Deserializer::from_str`,
+    // not `std::str::FromStr`.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(input: &'a str) -> Self {
+        Deserializer::new(SliceRead::new(input.as_bytes()))
+    }
+}
+
+impl<R: io::Read> Deserializer<IoRead<R>> {
+    pub fn from_reader(reader: R) -> Self {
+        Deserializer::new(IoRead::new(reader))
+    }
+}
+
+impl<R: Read> Deserializer<R> {
+    /// Rejects Hjson objects that repeat the same key twice instead of
+    /// letting the later value silently win.
+    pub fn set_strict_keys(&mut self, strict_keys: bool) {
+        self.strict_keys = strict_keys;
+    }
+
+    /// Toggles support for Java-style `/* */` block comments.
+    pub fn set_allow_block_comments(&mut self, allow_block_comments: bool) {
+        self.allow_block_comments = allow_block_comments;
+    }
+
+    /// Sets how many objects/arrays deep a document may nest before
+    /// [`Error::DepthLimitExceeded`] is raised, guarding against
+    /// adversarial deeply-nested input overflowing the stack.
+    pub fn set_max_depth(&mut self, max_depth: Option<usize>) {
+        self.max_depth = max_depth;
+    }
+
+    fn new(read: R) -> Self {
+        Deserializer {
+            read,
+            line: 1,
+            col: 1,
+            at_root: true,
+            strict_keys: false,
+            allow_block_comments: true,
+            depth: 0,
+            max_depth: None,
+        }
+    }
+
+    fn enter_container(&mut self) -> Result<()> {
+        self.depth += 1;
+        if let Some(max_depth) = self.max_depth {
+            if self.depth > max_depth {
+                return Err(Error::DepthLimitExceeded);
+            }
+        }
+        Ok(())
+    }
+
+    fn exit_container(&mut self) {
+        self.depth -= 1;
+    }
+
+    /// The line/column the parser is currently positioned at.
+    ///
+    /// Because every parse error is propagated immediately via `?`
+    /// without consuming any further input, calling this right after a
+    /// `Deserialize` call failed yields the same position at which the
+    /// error was actually produced.
+    pub fn position(&self) -> Position {
+        Position { line: self.line, col: self.col }
+    }
+
+    fn peek_byte(&mut self) -> Result<Option<u8>> {
+        self.read.peek(0)
+    }
+
+    fn peek_byte_at(&mut self, offset: usize) -> Result<Option<u8>> {
+        self.read.peek(offset)
+    }
+
+    fn bump(&mut self) -> Result<Option<u8>> {
+        let byte = self.read.next()?;
+        if let Some(b'\n') = byte {
+            self.line += 1;
+            self.col = 1;
+        } else if let Some(b) = byte {
+            // UTF-8 continuation bytes (10xxxxxx) don't start a new
+            // character, so they must not advance the column; this keeps
+            // `col` a character count rather than a byte count.
+            if b & 0xC0 != 0x80 {
+                self.col += 1;
+            }
+        }
+        Ok(byte)
+    }
+
+    fn skip_whitespace_and_comments(&mut self) -> Result<()> {
+        loop {
+            match self.peek_byte()? {
+                Some(b' ') | Some(b'\t') | Some(b'\r') | Some(b'\n') => {
+                    self.bump()?;
+                }
+                Some(b'#') => {
+                    while !matches!(self.peek_byte()?, None | Some(b'\n')) {
+                        self.bump()?;
+                    }
+                }
+                Some(b'/') if self.peek_byte_at(1)? == Some(b'/') => {
+                    while !matches!(self.peek_byte()?, None | Some(b'\n')) {
+                        self.bump()?;
+                    }
+                }
+                Some(b'/') if self.allow_block_comments && self.peek_byte_at(1)? == Some(b'*') => {
+                    self.bump()?;
+                    self.bump()?;
+                    loop {
+                        match self.peek_byte()? {
+                            None => break,
+                            Some(b'*') if self.peek_byte_at(1)? == Some(b'/') => {
+                                self.bump()?;
+                                self.bump()?;
+                                break;
+                            }
+                            _ => {
+                                self.bump()?;
+                            }
+                        }
+                    }
+                }
+                _ => break,
+            }
+        }
+        Ok(())
+    }
+
+    fn eat_char(&mut self, expected: u8) -> Result<()> {
+        if self.peek_byte()? == Some(expected) {
+            self.bump()?;
+            Ok(())
+        } else {
+            Err(match expected {
+                b':' => Error::ExpectedColon,
+                b'{' => Error::ExpectedObject,
+                b'[' => Error::ExpectedArray,
+                b'}' => Error::ExpectedObjectCommaOrEnd,
+                b']' => Error::ExpectedArrayCommaOrEnd,
+                _ => Error::ExpectedValue,
+            })
+        }
+    }
+
+    fn is_triple_quote(&mut self) -> Result<bool> {
+        Ok(self.peek_byte()? == Some(b'\'')
+            && self.peek_byte_at(1)? == Some(b'\'')
+            && self.peek_byte_at(2)? == Some(b'\''))
+    }
+
+    /// Parses a `"..."` quoted string, handling the standard JSON escape
+    /// sequences.
+    fn parse_quoted_string(&mut self) -> Result<String> {
+        self.bump()?; // opening quote
+        let mut out = String::new();
+        loop {
+            match self.bump()? {
+                None => return Err(Error::UnterminatedString),
+                Some(b'"') => return Ok(out),
+                Some(b'\\') => match self.bump()? {
+                    Some(b'"') => out.push('"'),
+                    Some(b'\\') => out.push('\\'),
+                    Some(b'/') => out.push('/'),
+                    Some(b'b') => out.push('\u{0008}'),
+                    Some(b'f') => out.push('\u{000C}'),
+                    Some(b'n') => out.push('\n'),
+                    Some(b'r') => out.push('\r'),
+                    Some(b't') => out.push('\t'),
+                    Some(b'u') => {
+                        let cp = self.parse_hex4()?;
+                        let ch = if (0xD800..=0xDBFF).contains(&cp) {
+                            // A high surrogate must be followed by a `\u`
+                            // low surrogate; combine the pair via the
+                            // standard UTF-16 formula to recover an
+                            // astral code point (e.g. emoji).
+                            if self.peek_byte()? != Some(b'\\') || self.peek_byte_at(1)? != Some(b'u') {
+                                return Err(Error::InvalidUnicodeCodePoint);
+                            }
+                            self.bump()?; // '\\'
+                            self.bump()?; // 'u'
+                            let low = self.parse_hex4()?;
+                            if !(0xDC00..=0xDFFF).contains(&low) {
+                                return Err(Error::InvalidUnicodeCodePoint);
+                            }
+                            let combined =
+                                0x10000 + ((cp as u32 - 0xD800) << 10) + (low as u32 - 0xDC00);
+                            char::from_u32(combined).ok_or(Error::InvalidUnicodeCodePoint)?
+                        } else {
+                            char::from_u32(cp as u32).ok_or(Error::InvalidUnicodeCodePoint)?
+                        };
+                        out.push(ch);
+                    }
+                    _ => return Err(Error::InvalidEscape),
+                },
+                Some(byte) if byte < 0x80 => out.push(byte as char),
+                Some(byte) => {
+                    // Re-assemble a UTF-8 multi-byte sequence. Input from
+                    // an `io::Read` is not checked for valid UTF-8; an
+                    // invalid sequence is replaced by U+FFFD.
+                    let extra = match byte {
+                        0xC0..=0xDF => 1,
+                        0xE0..=0xEF => 2,
+                        _ => 3,
+                    };
+                    let mut bytes = vec![byte];
+                    for _ in 0..extra {
+                        bytes.push(self.bump()?.ok_or(Error::UnterminatedString)?);
+                    }
+                    out.push_str(std::str::from_utf8(&bytes).unwrap_or("\u{FFFD}"));
+                }
+            }
+        }
+    }
+
+    fn parse_hex4(&mut self) -> Result<u16> {
+        let mut value = 0u16;
+        for _ in 0..4 {
+            let byte = self.bump()?.ok_or(Error::InvalidEscape)?;
+            let digit = match byte {
+                b'0'..=b'9' => byte - b'0',
+                b'a'..=b'f' => byte - b'a' + 10,
+                b'A'..=b'F' => byte - b'A' + 10,
+                _ => return Err(Error::InvalidEscape),
+            };
+            value = value * 16 + digit as u16;
+        }
+        Ok(value)
+    }
+
+    /// Parses a `'''...'''` multiline string, stripping the common
+    /// leading indentation (taken from the line the closing quotes sit
+    /// on) and the line break immediately before the closing quotes.
+    /// Everything after the opening quotes on their own line is skipped,
+    /// whether or not it is blank.
+    fn parse_multiline_string(&mut self) -> Result<String> {
+        self.bump()?;
+        self.bump()?;
+        self.bump()?; // opening '''
+                      // Skip to the end of the opening line.
+        while !matches!(self.peek_byte()?, None | Some(b'\n')) {
+            self.bump()?;
+        }
+        self.bump()?; // the newline itself, if any
+
+        let mut lines: Vec<Vec<u8>> = vec![Vec::new()];
+        let indent = loop {
+            if self.is_triple_quote()? {
+                let current = lines.last().unwrap();
+                if current.iter().all(|b| *b == b' ' || *b == b'\t') {
+                    let indent = current.len();
+                    lines.pop();
+                    self.bump()?;
+                    self.bump()?;
+                    self.bump()?; // closing '''
+                    break indent;
+                }
+            }
+            match self.bump()? {
+                None => return Err(Error::UnterminatedMultilineString),
+                Some(b'\n') => lines.push(Vec::new()),
+                Some(byte) => lines.last_mut().unwrap().push(byte),
+            }
+        };
+
+        let dedented: Vec<String> = lines
+            .into_iter()
+            .map(|line| {
+                let strip = line.len().min(indent);
+                let text = String::from_utf8_lossy(&line).into_owned();
+                if line[..strip].iter().all(|b| *b == b' ' || *b == b'\t') {
+                    text[strip..].to_owned()
+                } else {
+                    text.trim_start().to_owned()
+                }
+            })
+            .collect();
+        let mut text = dedented.join("\n");
+        while text.ends_with('\r') {
+            text.pop();
+        }
+        Ok(text)
+    }
+
+    /// Reads a bareword object/array member key up to its terminating
+    /// `:`, or a quoted string key.
+    fn parse_key(&mut self) -> Result<String> {
+        match self.peek_byte()? {
+            Some(b'"') => self.parse_quoted_string(),
+            _ => {
+                let mut raw = Vec::new();
+                while !matches!(self.peek_byte()?, None | Some(b':') | Some(b'\n')) {
+                    raw.push(self.bump()?.unwrap());
+                }
+                Ok(String::from_utf8_lossy(&raw).trim().to_owned())
+            }
+        }
+    }
+
+    /// Reads a quoteless scalar: everything up to the end of the current
+    /// line, the next `,`, or a `}`/`]`, trimmed of trailing whitespace. Used both for plain strings and,
+    /// in [`Deserializer::parse_any`], to decide whether the token
+    /// actually denotes a bool/null/number.
+    fn scan_quoteless(&mut self) -> Result<String> {
+        let mut raw = Vec::new();
+        loop {
+            match self.peek_byte()? {
+                None | Some(b'\n') | Some(b'}') | Some(b']') | Some(b',') => break,
+                _ => raw.push(self.bump()?.unwrap()),
+            }
+        }
+        Ok(String::from_utf8_lossy(&raw).trim_end().to_owned())
+    }
+
+    /// Scans a quoteless token that is expected to be a recognized
+    /// literal (`true`, `false`, `null`, or a number), tolerating a
+    /// trailing `#`/`//` comment on the same line instead of swallowing
+    /// it into the token, e.g. `int: -1 # this comment goes to end of
+    /// line`.
+    ///
+    /// If the rest of the line turns out not to be just a trailing
+    /// comment (or the leading word isn't a recognized literal), this
+    /// isn't a literal after all, so it falls back to
+    /// [`Deserializer::scan_quoteless`] and consumes the whole line as a
+    /// plain quoteless string, leaving classification to the caller.
+    fn scan_literal_token(&mut self) -> Result<String> {
+        let mut peeked = Vec::new();
+        loop {
+            match self.peek_byte_at(peeked.len())? {
+                None | Some(b'\n') | Some(b'}') | Some(b']') | Some(b',') => break,
+                Some(byte) => peeked.push(byte),
+            }
+        }
+        let line = String::from_utf8_lossy(&peeked).trim_end().to_owned();
+        let token_len = line.find(char::is_whitespace).unwrap_or(line.len());
+        let token = &line[..token_len];
+        let rest = line[token_len..].trim_start();
+        let only_trailing_comment = rest.is_empty() || rest.starts_with('#') || rest.starts_with("//");
+        let is_literal = token == "true" || token == "false" || token == "null" || Self::looks_like_number(token);
+
+        if only_trailing_comment && is_literal {
+            let token = token.to_owned();
+            for _ in 0..token.len() {
+                self.bump()?;
+            }
+            Ok(token)
+        } else {
+            self.scan_quoteless()
+        }
+    }
+
+    fn looks_like_number(raw: &str) -> bool {
+        if raw.is_empty() {
+            return false;
+        }
+        let mut chars = raw.chars().peekable();
+        if matches!(chars.peek(), Some('-') | Some('+')) {
+            chars.next();
+        }
+        let mut saw_digit = false;
+        while matches!(chars.peek(), Some(c) if c.is_ascii_digit()) {
+            chars.next();
+            saw_digit = true;
+        }
+        if chars.peek() == Some(&'.') {
+            chars.next();
+            while matches!(chars.peek(), Some(c) if c.is_ascii_digit()) {
+                chars.next();
+                saw_digit = true;
+            }
+        }
+        if matches!(chars.peek(), Some('e') | Some('E')) {
+            chars.next();
+            if matches!(chars.peek(), Some('-') | Some('+')) {
+                chars.next();
+            }
+            let mut saw_exp_digit = false;
+            while matches!(chars.peek(), Some(c) if c.is_ascii_digit()) {
+                chars.next();
+                saw_exp_digit = true;
+            }
+            if !saw_exp_digit {
+                return false;
+            }
+        }
+        saw_digit && chars.next().is_none()
+    }
+
+    /// Parses whichever scalar or container value comes next, without
+    /// any expectation from the caller about its shape, self-classifying
+    /// quoteless tokens into bool/null/number/string the same way the
+    /// rest of the parser does implicitly when the target type is
+    /// already known.
+    fn parse_any<'de, V>(&mut self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.skip_whitespace_and_comments()?;
+        match self.peek_byte()? {
+            Some(b'{') => self.parse_object(visitor),
+            Some(b'[') => self.parse_array(visitor),
+            Some(b'"') => visitor.visit_string(self.parse_quoted_string()?),
+            Some(b'\'') if self.is_triple_quote()? => visitor.visit_string(self.parse_multiline_string()?),
+            None => Err(Error::Eof),
+            _ => {
+                let raw = self.scan_literal_token()?;
+                match raw.as_str() {
+                    "true" => visitor.visit_bool(true),
+                    "false" => visitor.visit_bool(false),
+                    "null" => visitor.visit_unit(),
+                    _ if Self::looks_like_number(&raw) => {
+                        if let Ok(i) = raw.parse::<i64>() {
+                            visitor.visit_i64(i)
+                        } else if let Ok(f) = raw.parse::<f64>() {
+                            visitor.visit_f64(f)
+                        } else {
+                            visitor.visit_string(raw)
+                        }
+                    }
+                    _ => visitor.visit_string(raw),
+                }
+            }
+        }
+    }
+
+    fn parse_object<'de, V>(&mut self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.eat_char(b'{')?;
+        self.at_root = false;
+        self.enter_container()?;
+        let value = visitor.visit_map(ObjectAccess::new(self, Some(b'}')))?;
+        self.exit_container();
+        self.skip_whitespace_and_comments()?;
+        self.eat_char(b'}')?;
+        Ok(value)
+    }
+
+    fn parse_array<'de, V>(&mut self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.eat_char(b'[')?;
+        self.at_root = false;
+        self.enter_container()?;
+        let value = visitor.visit_seq(ArrayAccess::new(self))?;
+        self.exit_container();
+        self.skip_whitespace_and_comments()?;
+        self.eat_char(b']')?;
+        Ok(value)
+    }
+
+    fn parse_root_object<'de, V>(&mut self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.at_root = false;
+        self.enter_container()?;
+        let value = visitor.visit_map(ObjectAccess::new(self, None))?;
+        self.exit_container();
+        Ok(value)
+    }
+
+    fn parse_string_value(&mut self) -> Result<String> {
+        self.skip_whitespace_and_comments()?;
+        match self.peek_byte()? {
+            Some(b'"') => self.parse_quoted_string(),
+            Some(b'\'') if self.is_triple_quote()? => self.parse_multiline_string(),
+            None => Err(Error::Eof),
+            _ => self.scan_quoteless(),
+        }
+    }
+
+    pub(crate) fn end(&mut self) -> Result<()> {
+        self.skip_whitespace_and_comments()?;
+        if self.peek_byte()?.is_some() {
+            Err(Error::TrailingCharacters)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+macro_rules! deserialize_number {
+    ($method:ident, $visit:ident, $ty:ty) => {
+        fn $method<V>(self, visitor: V) -> Result<V::Value>
+        where
+            V: Visitor<'de>,
+        {
+            self.skip_whitespace_and_comments()?;
+            let raw = self.scan_literal_token()?;
+            let n: $ty = raw.parse().map_err(|_| Error::InvalidNumber)?;
+            visitor.$visit(n)
+        }
+    };
+}
+
+impl<'de, R: Read> de::Deserializer<'de> for &mut Deserializer<R> {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.parse_any(visitor)
+    }
+
+    fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.skip_whitespace_and_comments()?;
+        let raw = self.scan_literal_token()?;
+        match raw.as_str() {
+            "true" => visitor.visit_bool(true),
+            "false" => visitor.visit_bool(false),
+            _ => Err(Error::ExpectedValue),
+        }
+    }
+
+    deserialize_number!(deserialize_i8, visit_i8, i8);
+    deserialize_number!(deserialize_i16, visit_i16, i16);
+    deserialize_number!(deserialize_i32, visit_i32, i32);
+    deserialize_number!(deserialize_i64, visit_i64, i64);
+    deserialize_number!(deserialize_u8, visit_u8, u8);
+    deserialize_number!(deserialize_u16, visit_u16, u16);
+    deserialize_number!(deserialize_u32, visit_u32, u32);
+    deserialize_number!(deserialize_u64, visit_u64, u64);
+    deserialize_number!(deserialize_f32, visit_f32, f32);
+    deserialize_number!(deserialize_f64, visit_f64, f64);
+
+    fn deserialize_char<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        let s = self.parse_string_value()?;
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => visitor.visit_char(c),
+            _ => Err(Error::ExpectedString),
+        }
+    }
+
+    fn deserialize_str<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_string(self.parse_string_value()?)
+    }
+
+    fn deserialize_string<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_string(self.parse_string_value()?)
+    }
+
+    fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_byte_buf(self.parse_string_value()?.into_bytes())
+    }
+
+    fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_byte_buf(self.parse_string_value()?.into_bytes())
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.skip_whitespace_and_comments()?;
+        let is_null = self.peek_byte()? == Some(b'n')
+            && self.peek_byte_at(1)? == Some(b'u')
+            && self.peek_byte_at(2)? == Some(b'l')
+            && self.peek_byte_at(3)? == Some(b'l')
+            && !matches!(self.peek_byte_at(4)?, Some(b) if b.is_ascii_alphanumeric());
+        if is_null {
+            for _ in 0..4 {
+                self.bump()?;
+            }
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.skip_whitespace_and_comments()?;
+        let raw = self.scan_literal_token()?;
+        if raw == "null" {
+            visitor.visit_unit()
+        } else {
+            Err(Error::ExpectedValue)
+        }
+    }
+
+    fn deserialize_unit_struct<V>(self, _name: &'static str, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V>(self, _name: &'static str, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.skip_whitespace_and_comments()?;
+        self.parse_array(visitor)
+    }
+
+    fn deserialize_tuple<V>(self, _len: usize, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.skip_whitespace_and_comments()?;
+        if self.at_root && self.peek_byte()? != Some(b'{') {
+            return self.parse_root_object(visitor);
+        }
+        self.parse_object(visitor)
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.skip_whitespace_and_comments()?;
+        match self.peek_byte()? {
+            Some(b'{') => {
+                self.bump()?;
+                self.at_root = false;
+                self.enter_container()?;
+                self.skip_whitespace_and_comments()?;
+                let value = visitor.visit_enum(EnumObjectAccess { de: self })?;
+                self.exit_container();
+                self.skip_whitespace_and_comments()?;
+                self.eat_char(b'}')?;
+                Ok(value)
+            }
+            _ => {
+                let variant = self.parse_string_value()?;
+                visitor.visit_enum(variant.into_deserializer())
+            }
+        }
+    }
+
+    fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_string(self.parse_key()?)
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.parse_any(visitor)
+    }
+}
+
+/// [`MapAccess`] implementation driving both braced objects (`delim ==
+/// Some(b'}')`) and the brace-less root object (`delim == None`, ends at
+/// EOF).
+struct ObjectAccess<'a, R> {
+    de: &'a mut Deserializer<R>,
+    delim: Option<u8>,
+    first: bool,
+    seen_keys: Option<HashSet<String>>,
+}
+
+impl<'a, R: Read> ObjectAccess<'a, R> {
+    fn new(de: &'a mut Deserializer<R>, delim: Option<u8>) -> Self {
+        let seen_keys = de.strict_keys.then(HashSet::new);
+        ObjectAccess { de, delim, first: true, seen_keys }
+    }
+
+    fn at_end(&mut self) -> Result<bool> {
+        Ok(match self.delim {
+            Some(delim) => self.de.peek_byte()? == Some(delim),
+            None => self.de.peek_byte()?.is_none(),
+        })
+    }
+}
+
+impl<'a, 'de, R: Read> MapAccess<'de> for ObjectAccess<'a, R> {
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        self.de.skip_whitespace_and_comments()?;
+        if !self.first && self.de.peek_byte()? == Some(b',') {
+            self.de.bump()?;
+            self.de.skip_whitespace_and_comments()?;
+        }
+        self.first = false;
+        if self.at_end()? {
+            return Ok(None);
+        }
+        let key = self.de.parse_key()?;
+        if let Some(seen_keys) = &mut self.seen_keys {
+            if !seen_keys.insert(key.clone()) {
+                return Err(Error::DuplicateKey(key));
+            }
+        }
+        seed.deserialize(key.into_deserializer()).map(Some)
+    }
+
+    fn next_value_seed<S>(&mut self, seed: S) -> Result<S::Value>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        self.de.skip_whitespace_and_comments()?;
+        self.de.eat_char(b':')?;
+        self.de.skip_whitespace_and_comments()?;
+        seed.deserialize(&mut *self.de)
+    }
+}
+
+struct ArrayAccess<'a, R> {
+    de: &'a mut Deserializer<R>,
+    first: bool,
+}
+
+impl<'a, R: Read> ArrayAccess<'a, R> {
+    fn new(de: &'a mut Deserializer<R>) -> Self {
+        ArrayAccess { de, first: true }
+    }
+}
+
+impl<'a, 'de, R: Read> SeqAccess<'de> for ArrayAccess<'a, R> {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        self.de.skip_whitespace_and_comments()?;
+        if !self.first && self.de.peek_byte()? == Some(b',') {
+            self.de.bump()?;
+            self.de.skip_whitespace_and_comments()?;
+        }
+        self.first = false;
+        if self.de.peek_byte()? == Some(b']') {
+            return Ok(None);
+        }
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+}
+
+struct EnumObjectAccess<'a, R> {
+    de: &'a mut Deserializer<R>,
+}
+
+impl<'a, 'de, R: Read> EnumAccess<'de> for EnumObjectAccess<'a, R> {
+    type Error = Error;
+    type Variant = EnumObjectAccess<'a, R>;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant)>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let key = self.de.parse_key()?;
+        self.de.skip_whitespace_and_comments()?;
+        self.de.eat_char(b':')?;
+        let value = seed.deserialize(key.into_deserializer())?;
+        Ok((value, self))
+    }
+}
+
+impl<'a, 'de, R: Read> VariantAccess<'de> for EnumObjectAccess<'a, R> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        self.de.skip_whitespace_and_comments()?;
+        seed.deserialize(self.de)
+    }
+
+    fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        de::Deserializer::deserialize_tuple(self.de, len, visitor)
+    }
+
+    fn struct_variant<V>(self, fields: &'static [&'static str], visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        de::Deserializer::deserialize_struct(self.de, "", fields, visitor)
+    }
+}
+
+/// Deserializes `input` as an Hjson document into a value of type `T`.
+pub fn from_str<'a, T>(input: &'a str) -> Result<T>
+where
+    T: Deserialize<'a>,
+{
+    let mut deserializer = Deserializer::from_str(input);
+    let value = T::deserialize(&mut deserializer)?;
+    deserializer.end()?;
+    Ok(value)
+}
+
+/// Like [`from_str`], but reads the Hjson document incrementally from
+/// any [`io::Read`] instead of requiring the caller to buffer it into a
+/// `String` up front.
+pub fn from_reader<R, T>(reader: R) -> Result<T>
+where
+    R: io::Read,
+    T: de::DeserializeOwned,
+{
+    let mut deserializer = Deserializer::from_reader(reader);
+    let value = T::deserialize(&mut deserializer)?;
+    deserializer.end()?;
+    Ok(value)
+}
+
+/// Like [`from_str`], but on failure returns the [`Position`] in the
+/// input at which the error was produced, wrapped in a
+/// [`SpannedError`].
+pub fn from_str_spanned<'a, T>(input: &'a str) -> std::result::Result<T, SpannedError>
+where
+    T: Deserialize<'a>,
+{
+    let mut deserializer = Deserializer::from_str(input);
+    let result = T::deserialize(&mut deserializer).and_then(|value| {
+        deserializer.end()?;
+        Ok(value)
+    });
+    result.map_err(|code| SpannedError { code, position: deserializer.position() })
+}
+
+/// Like [`from_str`], but rejects objects that repeat the same key
+/// twice instead of letting the later value silently win.
+pub fn from_str_strict<'a, T>(input: &'a str) -> Result<T>
+where
+    T: Deserialize<'a>,
+{
+    let mut deserializer = Deserializer::from_str(input);
+    deserializer.set_strict_keys(true);
+    let value = T::deserialize(&mut deserializer)?;
+    deserializer.end()?;
+    Ok(value)
+}
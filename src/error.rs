@@ -0,0 +1,130 @@
+use std::fmt::{self, Display};
+
+use serde::{de, ser};
+
+/// Alias for a `Result` with the error type [`Error`].
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Everything that can go wrong while deserializing an Hjson document.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A catch-all for errors raised by `serde` itself (e.g. via
+    /// `Error::custom`, as `Deserialize` impls do when a value doesn't
+    /// match an expected shape).
+    Message(String),
+    /// The input ended before a value was fully parsed.
+    Eof,
+    /// A byte was encountered that cannot start any valid Hjson value.
+    ExpectedValue,
+    /// A string (quoted or quoteless) was expected.
+    ExpectedString,
+    /// A `'''` multiline string was opened but never closed.
+    UnterminatedMultilineString,
+    /// A quoted string was opened but never closed.
+    UnterminatedString,
+    /// An invalid `\` escape sequence was found in a quoted string.
+    InvalidEscape,
+    /// A `\u` escape did not encode a valid Unicode code point.
+    InvalidUnicodeCodePoint,
+    /// A number could not be parsed.
+    InvalidNumber,
+    /// A `:` was expected after an object key.
+    ExpectedColon,
+    /// A `{` was expected to start an object.
+    ExpectedObject,
+    /// A `}` was expected to close an object, or a `,`/newline to
+    /// separate its members.
+    ExpectedObjectCommaOrEnd,
+    /// A `[` was expected to start an array.
+    ExpectedArray,
+    /// A `]` was expected to close an array, or a `,`/newline to
+    /// separate its members.
+    ExpectedArrayCommaOrEnd,
+    /// Extra, non-whitespace data was found after the top-level value.
+    TrailingCharacters,
+    /// The same key appeared twice within a single object while strict
+    /// duplicate-key checking was enabled.
+    DuplicateKey(String),
+    /// A document was nested deeper than the configured `max_depth`.
+    DepthLimitExceeded,
+    /// The underlying [`std::io::Read`] source returned an error.
+    Io(String),
+}
+
+impl de::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        Error::Message(msg.to_string())
+    }
+}
+
+impl ser::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        Error::Message(msg.to_string())
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Message(msg) => f.write_str(msg),
+            Error::Eof => f.write_str("unexpected end of input"),
+            Error::ExpectedValue => f.write_str("expected an Hjson value"),
+            Error::ExpectedString => f.write_str("expected a string"),
+            Error::UnterminatedMultilineString => f.write_str("unterminated multiline string"),
+            Error::UnterminatedString => f.write_str("unterminated string"),
+            Error::InvalidEscape => f.write_str("invalid escape sequence"),
+            Error::InvalidUnicodeCodePoint => f.write_str("invalid unicode code point"),
+            Error::InvalidNumber => f.write_str("invalid number"),
+            Error::ExpectedColon => f.write_str("expected ':' after object key"),
+            Error::ExpectedObject => f.write_str("expected '{'"),
+            Error::ExpectedObjectCommaOrEnd => f.write_str("expected ',', newline or '}'"),
+            Error::ExpectedArray => f.write_str("expected '['"),
+            Error::ExpectedArrayCommaOrEnd => f.write_str("expected ',', newline or ']'"),
+            Error::TrailingCharacters => f.write_str("trailing characters after top-level value"),
+            Error::DuplicateKey(key) => write!(f, "duplicate key: {:?}", key),
+            Error::DepthLimitExceeded => f.write_str("recursion/nesting depth limit exceeded"),
+            Error::Io(msg) => write!(f, "I/O error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A 1-based line/column position within an Hjson document.
+///
+/// Lines and columns both start at 1, matching the convention of most
+/// editors and of the error messages produced by other Hjson/JSON
+/// tooling.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub col: usize,
+}
+
+impl Default for Position {
+    fn default() -> Self {
+        Position { line: 1, col: 1 }
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {} column {}", self.line, self.col)
+    }
+}
+
+/// An [`Error`] together with the [`Position`] in the input at which it
+/// was produced, as returned by [`crate::from_str_spanned`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpannedError {
+    pub code: Error,
+    pub position: Position,
+}
+
+impl Display for SpannedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at {}", self.code, self.position)
+    }
+}
+
+impl std::error::Error for SpannedError {}
@@ -0,0 +1,35 @@
+//! A [serde](https://serde.rs) deserializer for [Hjson](https://hjson.github.io/),
+//! the human-friendly superset of JSON.
+//!
+//! ```
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize, Debug, PartialEq)]
+//! struct Config {
+//!     name: String,
+//!     port: u16,
+//! }
+//!
+//! let hjson = r#"{
+//!     ## comments are allowed
+//!     name: my-server
+//!     port: 8080
+//! }"#;
+//!
+//! let config: Config = deser_hjson::from_str(hjson).unwrap();
+//! assert_eq!(config, Config { name: "my-server".to_owned(), port: 8080 });
+//! ```
+
+mod de;
+mod error;
+mod options;
+mod read;
+mod value;
+
+#[cfg(test)]
+mod test;
+
+pub use crate::de::{from_reader, from_str, from_str_spanned, from_str_strict, Deserializer};
+pub use crate::error::{Error, Position, Result, SpannedError};
+pub use crate::options::Options;
+pub use crate::value::{Map, Number, Value};
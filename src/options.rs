@@ -0,0 +1,95 @@
+use std::io;
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use crate::de::Deserializer;
+use crate::error::Result;
+use crate::read::Read;
+
+/// A fluent builder for deserialization behavior, for callers who need
+/// more than the defaults used by the free [`crate::from_str`] /
+/// [`crate::from_reader`] functions.
+///
+/// ```
+/// use deser_hjson::Options;
+///
+/// let options = Options::new().strict_keys(true).max_depth(128);
+/// let value: std::collections::HashMap<String, i32> =
+///     options.from_str("{a: 1, b: 2}").unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct Options {
+    max_depth: Option<usize>,
+    strict_keys: bool,
+    allow_block_comments: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options::new()
+    }
+}
+
+impl Options {
+    /// Creates an `Options` with the same defaults as the free
+    /// [`crate::from_str`] function: no depth limit, duplicate keys
+    /// silently overwrite, and `/* */` block comments are accepted.
+    pub fn new() -> Self {
+        Options { max_depth: None, strict_keys: false, allow_block_comments: true }
+    }
+
+    /// Rejects documents that nest objects/arrays deeper than
+    /// `max_depth`, instead of letting adversarial input recurse until
+    /// the stack overflows.
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Rejects objects that repeat the same key twice instead of
+    /// letting the later value silently win.
+    pub fn strict_keys(mut self, strict_keys: bool) -> Self {
+        self.strict_keys = strict_keys;
+        self
+    }
+
+    /// Toggles support for Java-style `/* */` block comments.
+    pub fn allow_block_comments(mut self, allow_block_comments: bool) -> Self {
+        self.allow_block_comments = allow_block_comments;
+        self
+    }
+
+    fn configure<R: Read>(&self, deserializer: &mut Deserializer<R>) {
+        deserializer.set_max_depth(self.max_depth);
+        deserializer.set_strict_keys(self.strict_keys);
+        deserializer.set_allow_block_comments(self.allow_block_comments);
+    }
+
+    /// Deserializes `input` as an Hjson document, honoring this
+    /// configuration.
+    pub fn from_str<'a, T>(&self, input: &'a str) -> Result<T>
+    where
+        T: Deserialize<'a>,
+    {
+        let mut deserializer = Deserializer::from_str(input);
+        self.configure(&mut deserializer);
+        let value = T::deserialize(&mut deserializer)?;
+        deserializer.end()?;
+        Ok(value)
+    }
+
+    /// Deserializes an Hjson document read incrementally from `reader`,
+    /// honoring this configuration.
+    pub fn from_reader<R, T>(&self, reader: R) -> Result<T>
+    where
+        R: io::Read,
+        T: DeserializeOwned,
+    {
+        let mut deserializer = Deserializer::from_reader(reader);
+        self.configure(&mut deserializer);
+        let value = T::deserialize(&mut deserializer)?;
+        deserializer.end()?;
+        Ok(value)
+    }
+}
@@ -0,0 +1,80 @@
+use std::collections::VecDeque;
+use std::io;
+
+use crate::error::{Error, Result};
+
+/// A source of bytes the parser can pull from, with enough lookahead to
+/// tell apart `#`, `//` and `/*` comments, `'''` multiline strings and
+/// the `null`/`true`/`false` literals from quoteless text.
+///
+/// This is implemented once for an in-memory `&str` (used by
+/// [`crate::from_str`]) and once for any [`io::Read`] (used by
+/// [`crate::from_reader`]), so the parser itself doesn't need to care
+/// which kind of input it was handed.
+pub trait Read {
+    fn next(&mut self) -> Result<Option<u8>>;
+    fn peek(&mut self, lookahead: usize) -> Result<Option<u8>>;
+}
+
+/// Reads straight out of a borrowed byte slice.
+pub struct SliceRead<'a> {
+    slice: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> SliceRead<'a> {
+    pub fn new(slice: &'a [u8]) -> Self {
+        SliceRead { slice, pos: 0 }
+    }
+}
+
+impl<'a> Read for SliceRead<'a> {
+    fn next(&mut self) -> Result<Option<u8>> {
+        let byte = self.slice.get(self.pos).copied();
+        if byte.is_some() {
+            self.pos += 1;
+        }
+        Ok(byte)
+    }
+
+    fn peek(&mut self, lookahead: usize) -> Result<Option<u8>> {
+        Ok(self.slice.get(self.pos + lookahead).copied())
+    }
+}
+
+/// Reads from any [`io::Read`], buffering just enough bytes to satisfy
+/// the parser's lookahead needs.
+pub struct IoRead<R> {
+    inner: R,
+    buf: VecDeque<u8>,
+}
+
+impl<R: io::Read> IoRead<R> {
+    pub fn new(inner: R) -> Self {
+        IoRead { inner, buf: VecDeque::new() }
+    }
+
+    fn fill_to(&mut self, lookahead: usize) -> Result<()> {
+        let mut byte = [0u8; 1];
+        while self.buf.len() <= lookahead {
+            match self.inner.read(&mut byte) {
+                Ok(0) => break,
+                Ok(_) => self.buf.push_back(byte[0]),
+                Err(e) => return Err(Error::Io(e.to_string())),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<R: io::Read> Read for IoRead<R> {
+    fn next(&mut self) -> Result<Option<u8>> {
+        self.fill_to(0)?;
+        Ok(self.buf.pop_front())
+    }
+
+    fn peek(&mut self, lookahead: usize) -> Result<Option<u8>> {
+        self.fill_to(lookahead)?;
+        Ok(self.buf.get(lookahead).copied())
+    }
+}
@@ -0,0 +1,7 @@
+mod test_derived;
+mod test_options;
+mod test_parsing;
+mod test_reader;
+mod test_spanned;
+mod test_strict;
+mod test_value;
@@ -215,6 +215,7 @@ fn test_string() {
         }"#).unwrap(),
     );
     assert_eq!(W{c:"\x0C\x0C".to_string()}, from_str("{c:\"\\f\\u000C\"}").unwrap());
+    assert_eq!(W{c:"\u{1F600}".to_string()}, from_str("{c:\"\\uD83D\\uDE00\"}").unwrap());
 }
 
 #[test]
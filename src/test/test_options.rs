@@ -0,0 +1,39 @@
+use crate::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[test]
+fn test_options_strict_keys() {
+    let hjson = "{\n  a: 1\n  a: 2\n}";
+    let options = Options::new().strict_keys(true);
+    match options.from_str::<HashMap<String, i32>>(hjson) {
+        Err(Error::DuplicateKey(key)) => assert_eq!(key, "a"),
+        other => panic!("expected DuplicateKey(\"a\"), got {:?}", other),
+    }
+}
+
+#[test]
+fn test_options_max_depth() {
+    let options = Options::new().max_depth(2);
+    let err = options.from_str::<Value>("[[[1]]]").unwrap_err();
+    assert_eq!(err, Error::DepthLimitExceeded);
+}
+
+#[test]
+fn test_options_disallow_block_comments() {
+    #[derive(Deserialize, PartialEq, Debug)]
+    struct W {
+        a: i32,
+    }
+    let hjson = "{/* a leading block comment */a: 1}";
+    assert_eq!(W { a: 1 }, Options::new().from_str::<W>(hjson).unwrap());
+    assert!(Options::new().allow_block_comments(false).from_str::<W>(hjson).is_err());
+}
+
+#[test]
+fn test_options_default_matches_new() {
+    // `Options::default()` must agree with `Options::new()` (and thus
+    // with `from_str`), in particular that block comments are allowed.
+    let hjson = "{/* a leading block comment */a: 1}";
+    assert!(Options::default().from_str::<HashMap<String, i32>>(hjson).is_ok());
+}
@@ -0,0 +1,38 @@
+use crate::*;
+use serde::Deserialize;
+
+#[test]
+fn test_compact_array() {
+    assert_eq!(vec![1, 2, 3], from_str::<Vec<i32>>("[1,2,3]").unwrap());
+}
+
+#[test]
+fn test_compact_object() {
+    use std::collections::HashMap;
+
+    let mut expected = HashMap::new();
+    expected.insert("a".to_owned(), 1);
+    expected.insert("b".to_owned(), 2);
+    assert_eq!(expected, from_str::<HashMap<String, i32>>("{a:1,b:2}").unwrap());
+}
+
+#[test]
+fn test_number_followed_by_trailing_comment() {
+    #[derive(Deserialize, PartialEq, Debug)]
+    struct W {
+        int: i32,
+    }
+    let hjson = "{\n  int: -1 # this comment goes to end of line\n}";
+    assert_eq!(W { int: -1 }, from_str(hjson).unwrap());
+}
+
+#[test]
+fn test_bool_and_null_followed_by_trailing_comment() {
+    #[derive(Deserialize, PartialEq, Debug)]
+    struct W {
+        flag: bool,
+        empty: Option<i32>,
+    }
+    let hjson = "{\n  flag: true // a comment\n  empty: null # another comment\n}";
+    assert_eq!(W { flag: true, empty: None }, from_str(hjson).unwrap());
+}
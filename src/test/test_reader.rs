@@ -0,0 +1,14 @@
+use crate::*;
+use serde::Deserialize;
+
+#[test]
+fn test_from_reader_matches_from_str() {
+    #[derive(Deserialize, PartialEq, Debug)]
+    struct Config {
+        name: String,
+        port: u16,
+    }
+    let hjson = b"{\n  name: my-server\n  port: 8080\n}";
+    let config: Config = from_reader(&hjson[..]).unwrap();
+    assert_eq!(config, Config { name: "my-server".to_owned(), port: 8080 });
+}
@@ -0,0 +1,23 @@
+use crate::*;
+use serde::Deserialize;
+
+#[test]
+fn test_from_str_spanned_reports_position_of_error() {
+    #[derive(Deserialize, PartialEq, Debug)]
+    struct W {
+        a: i32,
+    }
+    let hjson = "{\n  a: not-a-number\n}";
+    let err = from_str_spanned::<W>(hjson).unwrap_err();
+    assert_eq!(err.position, Position { line: 2, col: 18 });
+    assert_eq!(W { a: 1 }, from_str_spanned("{a:1}").unwrap());
+}
+
+#[test]
+fn test_from_str_spanned_succeeds_like_from_str() {
+    #[derive(Deserialize, PartialEq, Debug)]
+    struct W {
+        a: i32,
+    }
+    assert_eq!(W { a: 1 }, from_str_spanned("{a:1}").unwrap());
+}
@@ -0,0 +1,18 @@
+use crate::*;
+use std::collections::HashMap;
+
+#[test]
+fn test_strict_rejects_duplicate_key() {
+    let hjson = "{\n  a: 1\n  a: 2\n}";
+    match from_str_strict::<HashMap<String, i32>>(hjson) {
+        Err(Error::DuplicateKey(key)) => assert_eq!(key, "a"),
+        other => panic!("expected DuplicateKey(\"a\"), got {:?}", other),
+    }
+}
+
+#[test]
+fn test_non_strict_allows_duplicate_key() {
+    let hjson = "{\n  a: 1\n  a: 2\n}";
+    let map: HashMap<String, i32> = from_str(hjson).unwrap();
+    assert_eq!(map.get("a"), Some(&2));
+}
@@ -0,0 +1,55 @@
+use crate::*;
+
+#[test]
+fn test_value_classifies_scalars() {
+    let hjson = "{\n  int: -1 # a comment\n  name: hello\n  flag: true\n  nothing: null\n}";
+    let value: Value = from_str(hjson).unwrap();
+    match value {
+        Value::Object(map) => {
+            assert_eq!(map.get("int"), Some(&Value::Number(Number::Integer(-1))));
+            assert_eq!(map.get("name"), Some(&Value::String("hello".to_owned())));
+            assert_eq!(map.get("flag"), Some(&Value::Bool(true)));
+            assert_eq!(map.get("nothing"), Some(&Value::Null));
+        }
+        other => panic!("expected an object, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_value_array() {
+    let value: Value = from_str("[1, 2, 3]").unwrap();
+    assert_eq!(
+        value,
+        Value::Array(vec![
+            Value::Number(Number::Integer(1)),
+            Value::Number(Number::Integer(2)),
+            Value::Number(Number::Integer(3)),
+        ])
+    );
+}
+
+#[test]
+fn test_value_nested_map_with_quoteless_and_multiline_strings() {
+    let hjson = "{\n  name: a quoteless string\n  text:\n    '''\n    line one\n    line two\n    '''\n  inner: {\n    list: [1, a quoteless item, '''\n    multiline in array\n    ''']\n  }\n}";
+    let value: Value = from_str(hjson).unwrap();
+    match value {
+        Value::Object(map) => {
+            assert_eq!(map.get("name"), Some(&Value::String("a quoteless string".to_owned())));
+            assert_eq!(map.get("text"), Some(&Value::String("line one\nline two".to_owned())));
+            match map.get("inner") {
+                Some(Value::Object(inner)) => {
+                    assert_eq!(
+                        inner.get("list"),
+                        Some(&Value::Array(vec![
+                            Value::Number(Number::Integer(1)),
+                            Value::String("a quoteless item".to_owned()),
+                            Value::String("multiline in array".to_owned()),
+                        ]))
+                    );
+                }
+                other => panic!("expected a nested object, got {:?}", other),
+            }
+        }
+        other => panic!("expected an object, got {:?}", other),
+    }
+}